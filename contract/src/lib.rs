@@ -11,6 +11,7 @@ use tiny_keccak::Keccak;
 use pwasm_ethereum as eth;
 use pwasm_abi::types::*;
 use pwasm_abi_derive::eth_abi;
+use pwasm_std::Vec;
 
 // `TokenContract` is an interface definition of a contract.
 // The following example covers the minimal subset of ERC20 token standard.
@@ -34,7 +35,10 @@ use pwasm_abi_derive::eth_abi;
 // Then it invokes pwasm_std::eth::call on `contactAddress` and returns the result.
 #[eth_abi(Endpoint, Client)]
 pub trait TokenContract {
-	fn constructor(&mut self, _total_supply: U256);
+	/// `_chain_id` is bound into every signed-message hash used by `mintWithReceipt`/`permit`
+	/// (see `chain_id`), so sibling deployments of this contract on different chains must be
+	/// constructed with their own, distinct chain id.
+	fn constructor(&mut self, _total_supply: U256, _chain_id: U256);
 
 	/// What is the balance of a particular account?
 	#[constant]
@@ -45,7 +49,7 @@ pub trait TokenContract {
 	fn totalSupply(&mut self) -> U256;
 
 	/// Transfer the balance from owner's account to another account
-	fn transfer(&mut self, _to: Address, _amount: U256) -> bool;
+	fn transfer(&mut self, _to: Address, _amount: U256) -> Result<(), TokenError>;
 
 	/// Send _value amount of tokens from address _from to address _to
 	/// The transferFrom method is used for a withdraw workflow, allowing contracts to send
@@ -53,26 +57,189 @@ pub trait TokenContract {
 	/// fees in sub-currencies; the command should fail unless the _from account has
 	/// deliberately authorized the sender of the message via some mechanism; we propose
 	/// these standardized APIs for approval:
-	fn transferFrom(&mut self, _from: Address, _to: Address, _amount: U256) -> bool;
+	fn transferFrom(&mut self, _from: Address, _to: Address, _amount: U256) -> Result<(), TokenError>;
 
 	/// Allow _spender to withdraw from your account, multiple times, up to the _value amount.
 	/// If this function is called again it overwrites the current allowance with _value.
-	fn approve(&mut self, _spender: Address, _value: U256) -> bool;
+	fn approve(&mut self, _spender: Address, _value: U256) -> Result<(), TokenError>;
 
 	/// Check the amount of tokens spender have right to spend on behalf of owner
 	fn allowance(&mut self, _owner: Address, _spender: Address) -> U256;
 
+	/// Mint `_amount` tokens to `_to`, authorized by a receipt signed off-chain by the
+	/// bridge's authorized signer instead of an on-chain transaction from the owner.
+	/// `_nonce` must not have been consumed by a previous receipt, preventing replay.
+	fn mintWithReceipt(&mut self, _to: Address, _amount: U256, _nonce: U256, _sig: Vec<u8>) -> Result<(), TokenError>;
+
+	/// EIP-2612: set `_owner`'s allowance for `_spender` to `_value` from an off-chain
+	/// EIP-712 signature, so the owner does not have to pay gas for `approve` themselves.
+	fn permit(&mut self, _owner: Address, _spender: Address, _value: U256, _deadline: U256, _v: u8, _r: H256, _s: H256) -> Result<(), TokenError>;
+
+	/// The current EIP-2612 permit nonce for `_owner`, incremented by every successful `permit`.
+	#[constant]
+	fn nonces(&mut self, _owner: Address) -> U256;
+
+	/// The chain id this deployment was constructed with. Bound into every signed-message
+	/// hash used by `mintWithReceipt`/`permit` so a signature cannot be replayed against a
+	/// sibling deployment of this contract on another chain.
+	#[constant]
+	fn chain_id(&mut self) -> U256;
+
+	/// The account allowed to call `transferOwnership`, `mint` and `burn`.
+	#[constant]
+	fn owner(&mut self) -> Address;
+
+	/// Rotate ownership to `_new_owner`. Only callable by the current owner. `_new_owner`
+	/// must not be the zero address, since that would permanently lock out
+	/// `transferOwnership`/`mint`/`burn` with no way back.
+	fn transferOwnership(&mut self, _new_owner: Address) -> Result<(), TokenError>;
+
+	/// Create `_amount` new tokens for `_to`, increasing the total supply. Only callable
+	/// by the owner.
+	fn mint(&mut self, _to: Address, _amount: U256) -> Result<(), TokenError>;
+
+	/// Destroy `_amount` of the owner's own tokens, decreasing the total supply. Only
+	/// callable by the owner.
+	fn burn(&mut self, _amount: U256) -> Result<(), TokenError>;
+
+	#[event]
+	fn OwnershipTransferred(&mut self, indexed_previous: Address, indexed_new: Address);
 	#[event]
 	fn Transfer(&mut self, indexed_from: Address, indexed_to: Address, _value: U256);
 	#[event]
 	fn Approval(&mut self, indexed_owner: Address, indexed_spender: Address, _value: U256);
 }
 
+/// Failure modes for `transfer`/`transferFrom`/`approve` and the other state-changing
+/// calls below, returned instead of a bare `false`/panic so callers can tell exactly why
+/// a call failed.
+///
+/// FIXME(chunk0-1): the request this type was added for asks for more than this — it
+/// wants the `Endpoint` dispatch path to map `Err` to an EVM revert with an ABI-encoded
+/// error selector, instead of returning a plain `false` word. That part is NOT done.
+/// Every commit in this series only changed trait method signatures from `bool` to
+/// `Result<(), TokenError>`; none of them touch `#[eth_abi(Endpoint, Client)]`'s codegen,
+/// and nothing calls `Endpoint::dispatch` anywhere in this file's tests (they all call
+/// `TokenContractInstance`'s methods directly). Whether the generated dispatcher even
+/// compiles an `Err` arm for this enum, let alone reverts with it, is unknown and
+/// unverified here — this repo has no `Cargo.toml` pinning a `pwasm_abi_derive` version
+/// to check against. Land the dispatch-level change (and a test that drives it through
+/// `Endpoint::dispatch`) before treating this request as complete.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokenError {
+	InsufficientBalance,
+	InsufficientAllowance,
+	ZeroAmount,
+	SelfTransfer,
+	Unauthorized,
+	InvalidSignature,
+	NonceAlreadyUsed,
+	DeadlineExpired,
+	ZeroAddress,
+}
+
 lazy_static::lazy_static! {
 	static ref TOTAL_SUPPLY_KEY: H256 =
 		H256::from([2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]);
 	static ref OWNER_KEY: H256 =
 		H256::from([3,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]);
+	// The off-chain bridge operator whose signature authorizes `mintWithReceipt` calls.
+	static ref AUTHORIZED_SIGNER_KEY: H256 =
+		H256::from([4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]);
+	// The chain id this deployment was constructed with. Fixed once in `constructor` like
+	// `OWNER_KEY`, so every signed-message hash can bind to it and reject cross-chain replay.
+	static ref CHAIN_ID_KEY: H256 =
+		H256::from([5,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]);
+	// The EIP-712 domain separator for this deployment, computed once in `constructor`
+	// after `chain_id` is known and cached here so `permit` doesn't redo three `keccak256`
+	// calls on every invocation.
+	static ref DOMAIN_SEPARATOR_KEY: H256 =
+		H256::from([7,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]);
+}
+
+// Address of the `ecrecover` precompile, as specified by the Ethereum yellow paper.
+const ECRECOVER_ADDRESS: [u8; 20] = [0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1];
+// Gas stipend for the `ecrecover` precompile call; well above its fixed cost of 3000.
+const ECRECOVER_GAS: u64 = 10_000;
+
+// EIP-712 domain values for this token.
+const TOKEN_NAME: &str = "PwasmToken";
+const TOKEN_VERSION: &str = "1";
+
+// keccak256 of an arbitrary byte string. Used to build the EIP-712 typehashes and the
+// hashes of the domain's `name`/`version` strings.
+fn keccak256(data: &[u8]) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update(data);
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+// keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+fn eip712_domain_typehash() -> H256 {
+	keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+}
+
+// keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+fn permit_typehash() -> H256 {
+	keccak256(b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+}
+
+// Computes the EIP-712 domain separator for this deployment. Called once from
+// `constructor` to populate `DOMAIN_SEPARATOR_KEY`; `permit` reads the cached value via
+// `read_domain_separator` instead of recomputing it on every call.
+fn compute_domain_separator(chain_id: U256) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update(eip712_domain_typehash().as_bytes());
+	keccak.update(keccak256(TOKEN_NAME.as_bytes()).as_bytes());
+	keccak.update(keccak256(TOKEN_VERSION.as_bytes()).as_bytes());
+	keccak.update(H256::from(chain_id).as_bytes());
+	keccak.update(H256::from(eth::address()).as_bytes());
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+// keccak256(0x1901 || domainSeparator || structHash), the final digest an EIP-712
+// signer actually signs.
+fn eip712_digest(domain_separator: &H256, struct_hash: &H256) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update(&[0x19, 0x01]);
+	keccak.update(domain_separator.as_bytes());
+	keccak.update(struct_hash.as_bytes());
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+// keccak256(PERMIT_TYPEHASH || owner || spender || value || nonce || deadline)
+fn permit_struct_hash(owner: &Address, spender: &Address, value: &U256, nonce: &U256, deadline: &U256) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update(permit_typehash().as_bytes());
+	keccak.update(H256::from(*owner).as_bytes());
+	keccak.update(H256::from(*spender).as_bytes());
+	keccak.update(H256::from(*value).as_bytes());
+	keccak.update(H256::from(*nonce).as_bytes());
+	keccak.update(H256::from(*deadline).as_bytes());
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+// Generates the storage key for an owner's EIP-2612 permit nonce.
+fn nonce_key(owner: &Address) -> H256 {
+	let mut key = H256::from(*owner);
+	key.as_bytes_mut()[0] = 6; // namespace, distinct from balance_key's
+	key
+}
+
+fn read_nonce(owner: &Address) -> U256 {
+	U256::from_big_endian(&eth::read(&nonce_key(owner)))
+}
+
+fn write_nonce(owner: &Address, value: U256) {
+	eth::write(&nonce_key(owner), &value.into())
 }
 
 // Reads balance by address
@@ -111,10 +278,97 @@ fn allowance_key(owner: &Address, spender: &Address) -> H256 {
 	res
 }
 
+// Generates the storage key that tracks whether a bridge receipt `nonce` has been redeemed.
+fn receipt_nonce_key(nonce: &U256) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update("receipt_nonce".as_ref());
+	keccak.update(H256::from(*nonce).as_bytes());
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+// Returns true if `nonce` has already been consumed by a previous `mintWithReceipt` call.
+fn is_nonce_used(nonce: &U256) -> bool {
+	eth::read(&receipt_nonce_key(nonce)) != [0u8; 32]
+}
+
+// Marks `nonce` as consumed so the receipt that carries it cannot be replayed.
+fn mark_nonce_used(nonce: &U256) {
+	eth::write(&receipt_nonce_key(nonce), &U256::from(1).into());
+}
+
+// keccak256(to || amount_be || nonce_be || contract_address || chain_id_be), the message a
+// bridge operator signs off-chain to authorize a `mintWithReceipt` call. Binding chain_id
+// stops a receipt signed for one deployment from being replayed against a sibling
+// deployment of this contract on another chain.
+fn receipt_hash(to: &Address, amount: &U256, nonce: &U256, chain_id: U256) -> H256 {
+	let mut keccak = Keccak::new_keccak256();
+	let mut res = H256::zero();
+	keccak.update(to.as_ref());
+	keccak.update(H256::from(*amount).as_bytes());
+	keccak.update(H256::from(*nonce).as_bytes());
+	keccak.update(eth::address().as_ref());
+	keccak.update(H256::from(chain_id).as_bytes());
+	keccak.finalize(res.as_bytes_mut());
+	res
+}
+
+fn read_chain_id() -> U256 {
+	U256::from_big_endian(&eth::read(&CHAIN_ID_KEY))
+}
+
+fn read_domain_separator() -> H256 {
+	H256::from(eth::read(&DOMAIN_SEPARATOR_KEY))
+}
+
+fn read_owner() -> Address {
+	Address::from(H256::from(eth::read(&OWNER_KEY)))
+}
+
+// Recovers the signer address of `hash` from an (r, s, v) ECDSA signature by calling the
+// `ecrecover` precompile at address 0x01 with input `hash || v || r || s`. Returns
+// `TokenError::InvalidSignature` rather than panicking if the precompile call itself
+// fails, since not every host (e.g. a Substrate EVM pallet without it wired up) is
+// guaranteed to have it available.
+fn ecrecover(hash: &H256, v: u8, r: &H256, s: &H256) -> Result<Address, TokenError> {
+	let mut input = Vec::with_capacity(128);
+	input.extend_from_slice(hash.as_bytes());
+	let mut v_word = [0u8; 32];
+	v_word[31] = v;
+	input.extend_from_slice(&v_word);
+	input.extend_from_slice(r.as_bytes());
+	input.extend_from_slice(s.as_bytes());
+
+	let mut output = [0u8; 32];
+	eth::call(ECRECOVER_GAS, &Address::from(ECRECOVER_ADDRESS), U256::zero(), &input, &mut output)
+		.map_err(|_| TokenError::InvalidSignature)?;
+	Ok(Address::from(H256::from(output)))
+}
+
+// `ecrecover`'s real precompile returns an all-zero result (not a call failure) for
+// malformed recovery params, e.g. `v` outside {27,28}, which surfaces here as
+// `signer == Address::zero()` rather than an `Err` from `ecrecover()` above. `permit`
+// takes `owner` from the caller, so without this check `permit(Address::zero(), ...)`
+// would recover a zero signer that trivially equals a zero owner and pass with no real
+// signature at all.
+fn is_valid_permit_signer(signer: Address, owner: Address) -> bool {
+	signer != Address::zero() && signer == owner
+}
+
+// Increases `to`'s balance and the total supply by `amount`. Callers are responsible for
+// emitting the corresponding `Transfer(0x0, to, amount)` event.
+fn mint_to(to: &Address, amount: U256) {
+	let new_balance = read_balance_of(to) + amount;
+	eth::write(&balance_key(to), &new_balance.into());
+	let new_total_supply = U256::from_big_endian(&eth::read(&TOTAL_SUPPLY_KEY)) + amount;
+	eth::write(&TOTAL_SUPPLY_KEY, &new_total_supply.into());
+}
+
 pub struct TokenContractInstance;
 
 impl TokenContract for TokenContractInstance {
-	fn constructor(&mut self, total_supply: U256) {
+	fn constructor(&mut self, total_supply: U256, chain_id: U256) {
 		let sender = eth::sender();
 		// Set up the total supply for the token
 		eth::write(&TOTAL_SUPPLY_KEY, &total_supply.into());
@@ -122,6 +376,15 @@ impl TokenContract for TokenContractInstance {
 		eth::write(&balance_key(&sender), &total_supply.into());
 		// Set the contract owner
 		eth::write(&OWNER_KEY, &H256::from(sender).into());
+		// The deployer is the initial authorized bridge signer for `mintWithReceipt`
+		eth::write(&AUTHORIZED_SIGNER_KEY, &H256::from(sender).into());
+		// Fix the chain id this deployment binds its signed messages to. Passed in by the
+		// deployer rather than hardcoded, so sibling deployments on different chains (e.g.
+		// Ethereum vs. a Substrate EVM pallet) get genuinely distinct signed-message hashes.
+		eth::write(&CHAIN_ID_KEY, &chain_id.into());
+		// Cache the EIP-712 domain separator now that chain_id is known, so `permit` reads
+		// it back instead of recomputing three `keccak256` calls on every invocation.
+		eth::write(&DOMAIN_SEPARATOR_KEY, &compute_domain_separator(chain_id).into());
 	}
 
 	fn balanceOf(&mut self, owner: Address) -> U256 {
@@ -132,50 +395,168 @@ impl TokenContract for TokenContractInstance {
 		U256::from_big_endian(&eth::read(&TOTAL_SUPPLY_KEY))
 	}
 
-	fn transfer(&mut self, to: Address, amount: U256) -> bool {
+	fn transfer(&mut self, to: Address, amount: U256) -> Result<(), TokenError> {
 		let sender = eth::sender();
+		if amount == 0.into() {
+			return Err(TokenError::ZeroAmount);
+		}
+		if to == sender {
+			return Err(TokenError::SelfTransfer);
+		}
 		let senderBalance = read_balance_of(&sender);
-		let recipientBalance = read_balance_of(&to);
-		if amount == 0.into() || senderBalance < amount || to == sender {
-			false
-		} else {
-			let new_sender_balance = senderBalance - amount;
-			let new_recipient_balance = recipientBalance + amount;
-			// TODO: impl From<U256> for H256 makes convertion to big endian. Could be optimized
-			eth::write(&balance_key(&sender), &new_sender_balance.into());
-			eth::write(&balance_key(&to), &new_recipient_balance.into());
-			self.Transfer(sender, to, amount);
-			true
+		if senderBalance < amount {
+			return Err(TokenError::InsufficientBalance);
 		}
+		let recipientBalance = read_balance_of(&to);
+		let new_sender_balance = senderBalance - amount;
+		let new_recipient_balance = recipientBalance + amount;
+		// TODO: impl From<U256> for H256 makes convertion to big endian. Could be optimized
+		eth::write(&balance_key(&sender), &new_sender_balance.into());
+		eth::write(&balance_key(&to), &new_recipient_balance.into());
+		self.Transfer(sender, to, amount);
+		Ok(())
 	}
 
-	fn approve(&mut self, spender: Address, value: U256) -> bool {
+	fn approve(&mut self, spender: Address, value: U256) -> Result<(), TokenError> {
 		write_allowance(&allowance_key(&eth::sender(), &spender), value);
 		self.Approval(eth::sender(), spender, value);
-		true
+		Ok(())
 	}
 
 	fn allowance(&mut self, owner: Address, spender: Address) -> U256 {
 		read_allowance(&allowance_key(&owner, &spender))
 	}
 
-	fn transferFrom(&mut self, from: Address, to: Address, amount: U256) -> bool {
+	fn transferFrom(&mut self, from: Address, to: Address, amount: U256) -> Result<(), TokenError> {
+		if amount == 0.into() {
+			return Err(TokenError::ZeroAmount);
+		}
+		if to == from {
+			return Err(TokenError::SelfTransfer);
+		}
 		let fromBalance = read_balance_of(&from);
-		let recipientBalance = read_balance_of(&to);
+		if fromBalance < amount {
+			return Err(TokenError::InsufficientBalance);
+		}
 		let a_key = allowance_key(&from, &eth::sender());
 		let allowed = read_allowance(&a_key);
-		if  allowed < amount || amount == 0.into() || fromBalance < amount  || to == from {
-			false
-		} else {
-			let new_allowed = allowed - amount;
-			let new_from_balance = fromBalance - amount;
-			let new_recipient_balance = recipientBalance + amount;
-			eth::write(&a_key, &new_allowed.into());
-			eth::write(&balance_key(&from), &new_from_balance.into());
-			eth::write(&balance_key(&to), &new_recipient_balance.into());
-			self.Transfer(from, to, amount);
-			true
+		if allowed < amount {
+			return Err(TokenError::InsufficientAllowance);
+		}
+		let recipientBalance = read_balance_of(&to);
+		let new_allowed = allowed - amount;
+		let new_from_balance = fromBalance - amount;
+		let new_recipient_balance = recipientBalance + amount;
+		eth::write(&a_key, &new_allowed.into());
+		eth::write(&balance_key(&from), &new_from_balance.into());
+		eth::write(&balance_key(&to), &new_recipient_balance.into());
+		self.Transfer(from, to, amount);
+		Ok(())
+	}
+
+	fn mintWithReceipt(&mut self, to: Address, amount: U256, nonce: U256, sig: Vec<u8>) -> Result<(), TokenError> {
+		if sig.len() != 65 {
+			return Err(TokenError::InvalidSignature);
+		}
+		if is_nonce_used(&nonce) {
+			return Err(TokenError::NonceAlreadyUsed);
+		}
+
+		let r = H256::from_slice(&sig[0..32]);
+		let s = H256::from_slice(&sig[32..64]);
+		let v = sig[64];
+		let hash = receipt_hash(&to, &amount, &nonce, read_chain_id());
+		let signer = ecrecover(&hash, v, &r, &s)?;
+		let authorized = Address::from(H256::from(eth::read(&AUTHORIZED_SIGNER_KEY)));
+		if signer != authorized {
+			return Err(TokenError::Unauthorized);
+		}
+
+		// The receipt is valid: consume its nonce before minting so a reentrant call
+		// can't redeem it twice.
+		mark_nonce_used(&nonce);
+		mint_to(&to, amount);
+		self.Transfer(Address::zero(), to, amount);
+		Ok(())
+	}
+
+	fn permit(&mut self, owner: Address, spender: Address, value: U256, deadline: U256, v: u8, r: H256, s: H256) -> Result<(), TokenError> {
+		if U256::from(eth::timestamp()) > deadline {
+			return Err(TokenError::DeadlineExpired);
+		}
+
+		let nonce = read_nonce(&owner);
+		let struct_hash = permit_struct_hash(&owner, &spender, &value, &nonce, &deadline);
+		let domain_separator = read_domain_separator();
+		let digest = eip712_digest(&domain_separator, &struct_hash);
+		let signer = ecrecover(&digest, v, &r, &s)?;
+		if !is_valid_permit_signer(signer, owner) {
+			return Err(TokenError::Unauthorized);
+		}
+
+		write_nonce(&owner, nonce + U256::from(1));
+		write_allowance(&allowance_key(&owner, &spender), value);
+		self.Approval(owner, spender, value);
+		Ok(())
+	}
+
+	fn nonces(&mut self, owner: Address) -> U256 {
+		read_nonce(&owner)
+	}
+
+	fn chain_id(&mut self) -> U256 {
+		read_chain_id()
+	}
+
+	fn owner(&mut self) -> Address {
+		read_owner()
+	}
+
+	fn transferOwnership(&mut self, new_owner: Address) -> Result<(), TokenError> {
+		let current_owner = read_owner();
+		if eth::sender() != current_owner {
+			return Err(TokenError::Unauthorized);
+		}
+		// `owner()` is the sole gate for `transferOwnership`/`mint`/`burn`, and nothing can
+		// ever send a call as `Address::zero()`, so handing ownership to it would brick
+		// all three permanently.
+		if new_owner == Address::zero() {
+			return Err(TokenError::ZeroAddress);
+		}
+		eth::write(&OWNER_KEY, &H256::from(new_owner).into());
+		self.OwnershipTransferred(current_owner, new_owner);
+		Ok(())
+	}
+
+	fn mint(&mut self, to: Address, amount: U256) -> Result<(), TokenError> {
+		if eth::sender() != read_owner() {
+			return Err(TokenError::Unauthorized);
+		}
+		if amount == 0.into() {
+			return Err(TokenError::ZeroAmount);
+		}
+		mint_to(&to, amount);
+		self.Transfer(Address::zero(), to, amount);
+		Ok(())
+	}
+
+	fn burn(&mut self, amount: U256) -> Result<(), TokenError> {
+		let sender = eth::sender();
+		if sender != read_owner() {
+			return Err(TokenError::Unauthorized);
 		}
+		if amount == 0.into() {
+			return Err(TokenError::ZeroAmount);
+		}
+		let balance = read_balance_of(&sender);
+		if balance < amount {
+			return Err(TokenError::InsufficientBalance);
+		}
+		eth::write(&balance_key(&sender), &(balance - amount).into());
+		let new_total_supply = U256::from_big_endian(&eth::read(&TOTAL_SUPPLY_KEY)) - amount;
+		eth::write(&TOTAL_SUPPLY_KEY, &new_total_supply.into());
+		self.Transfer(sender, Address::zero(), amount);
+		Ok(())
 	}
 }
 
@@ -209,7 +590,7 @@ mod tests {
 		ext_reset(|e| e);
 		let mut contract = TokenContractInstance{};
 		let total_supply = 42.into();
-		contract.constructor(total_supply);
+		contract.constructor(total_supply, 1.into());
 		assert_eq!(contract.totalSupply(), total_supply);
 	}
 
@@ -221,7 +602,7 @@ mod tests {
 		let total_supply =
 			U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007913129639935").unwrap();
 		assert_eq!(total_supply, U256::max_value());
-		contract.constructor(total_supply);
+		contract.constructor(total_supply, 1.into());
 		assert_eq!(contract.totalSupply(), total_supply);
 	}
 
@@ -230,7 +611,7 @@ mod tests {
 		ext_reset(|e| e);
 		let mut contract = TokenContractInstance{};
 		let total_supply = 10000.into();
-		contract.constructor(total_supply);
+		contract.constructor(total_supply, 1.into());
 		assert_eq!(contract.balanceOf(ext_get().sender()), total_supply);
 	}
 
@@ -244,11 +625,11 @@ mod tests {
 		ext_reset(|e| e.sender(owner_address.clone()));
 
 		let total_supply = 10000.into();
-		contract.constructor(total_supply);
+		contract.constructor(total_supply, 1.into());
 
 		assert_eq!(contract.balanceOf(owner_address), total_supply);
 
-		assert_eq!(contract.transfer(sam_address, 1000.into()), true);
+		assert_eq!(contract.transfer(sam_address, 1000.into()), Ok(()));
 		assert_eq!(ext_get().logs().len(), 1);
 		assert_eq!(ext_get().logs()[0].topics.as_ref(), &[
 			// hash of the event name
@@ -268,8 +649,11 @@ mod tests {
 	fn should_return_false_transfer_not_sufficient_funds() {
 		ext_reset(|e| e);
 		let mut contract = TokenContractInstance{};
-		contract.constructor(10000.into());
-		assert_eq!(contract.transfer(Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap(), 50000.into()), false);
+		contract.constructor(10000.into(), 1.into());
+		assert_eq!(
+			contract.transfer(Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap(), 50000.into()),
+			Err(TokenError::InsufficientBalance)
+		);
 		assert_eq!(contract.balanceOf(::pwasm_ethereum::sender()), 10000.into());
 		assert_eq!(contract.balanceOf(Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap()), 0.into());
 		assert_eq!(ext_get().logs().len(), 0, "Should be no events created");
@@ -280,7 +664,7 @@ mod tests {
 		ext_reset(|e| e);
 		let mut contract = TokenContractInstance{};
 		let spender = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
-		contract.constructor(40000.into());
+		contract.constructor(40000.into(), 1.into());
 		contract.approve(spender, 40000.into());
 		assert_eq!(ext_get().logs().len(), 1, "Should be 1 event logged");
 		assert_eq!(ext_get().logs()[0].topics.as_ref(), &[
@@ -303,22 +687,25 @@ mod tests {
 			Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
 		let samAddress =
 			Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
-		contract.constructor(40000.into());
+		contract.constructor(40000.into(), 1.into());
 		contract.approve(spender, 10000.into());
 
 		// Build different external with sender = spender
 		ext_update(|e| e.sender(spender));
 
-		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 5000.into()), true);
+		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 5000.into()), Ok(()));
 		assert_eq!(contract.balanceOf(samAddress.clone()), 5000.into());
 		assert_eq!(contract.balanceOf(owner.clone()), 35000.into());
 
-		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 5000.into()), true);
+		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 5000.into()), Ok(()));
 		assert_eq!(contract.balanceOf(samAddress.clone()), 10000.into());
 		assert_eq!(contract.balanceOf(owner.clone()), 30000.into());
 
 		// The limit has reached. No more coins should be available to spend for the spender
-		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 1.into()), false);
+		assert_eq!(
+			contract.transferFrom(owner.clone(), samAddress.clone(), 1.into()),
+			Err(TokenError::InsufficientAllowance)
+		);
 		assert_eq!(contract.balanceOf(samAddress.clone()), 10000.into());
 		assert_eq!(contract.balanceOf(owner.clone()), 30000.into());
 		assert_eq!(ext_get().logs().len(), 3, "Two events should be created");
@@ -333,7 +720,7 @@ mod tests {
 			Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
 		let samAddress =
 			Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
-		contract.constructor(70000.into());
+		contract.constructor(70000.into(), 1.into());
 		contract.transfer(samAddress, 30000.into());
 		contract.approve(spender, 40000.into());
 
@@ -341,7 +728,10 @@ mod tests {
 		ext_update(|e| e.sender(spender));
 
 		// Despite of the allowance, can't transfer because the owner is out of tokens
-		assert_eq!(contract.transferFrom(owner.clone(), samAddress.clone(), 40001.into()), false);
+		assert_eq!(
+			contract.transferFrom(owner.clone(), samAddress.clone(), 40001.into()),
+			Err(TokenError::InsufficientBalance)
+		);
 		assert_eq!(contract.balanceOf(samAddress.clone()), 30000.into());
 		assert_eq!(contract.balanceOf(owner.clone()), 40000.into());
 		assert_eq!(ext_get().logs().len(), 2, "Should be no events created");
@@ -354,11 +744,255 @@ mod tests {
 			Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
 		ext_reset(|e| e.sender(owner_address.clone()));
 		let total_supply = 10000.into();
-		contract.constructor(total_supply);
+		contract.constructor(total_supply, 1.into());
 		assert_eq!(contract.balanceOf(owner_address), total_supply);
-		assert_eq!(contract.transfer(owner_address, 1000.into()), false);
-		assert_eq!(contract.transferFrom(owner_address, owner_address, 1000.into()), false);
+		assert_eq!(contract.transfer(owner_address, 1000.into()), Err(TokenError::SelfTransfer));
+		assert_eq!(contract.transferFrom(owner_address, owner_address, 1000.into()), Err(TokenError::SelfTransfer));
 		assert_eq!(contract.balanceOf(owner_address), 10000.into());
 		assert_eq!(ext_get().logs().len(), 0);
 	}
+
+	#[test]
+	fn chain_id_should_be_the_value_passed_to_construction() {
+		ext_reset(|e| e);
+		let mut contract = TokenContractInstance{};
+		contract.constructor(1000.into(), 42.into());
+		assert_eq!(contract.chain_id(), 42.into());
+	}
+
+	#[test]
+	fn sibling_deployments_with_different_chain_ids_hash_receipts_differently() {
+		ext_reset(|e| e);
+		let mut contract_a = TokenContractInstance{};
+		contract_a.constructor(1000.into(), 1.into());
+		// `ext_reset` below wipes this deployment's storage, so read its chain id first.
+		let chain_id_a = contract_a.chain_id();
+
+		ext_reset(|e| e);
+		let mut contract_b = TokenContractInstance{};
+		contract_b.constructor(1000.into(), 2.into());
+
+		// Two real, separately-constructed deployments with distinct chain ids produce
+		// different signed-message hashes from the chain id each one stored, which is what makes a
+		// receipt signed against one deployment's hash recover a different (and therefore
+		// rejected) signer address against the other's.
+		let to = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		let amount = 500.into();
+		let nonce = 1.into();
+		let hash_for_a = receipt_hash(&to, &amount, &nonce, chain_id_a);
+		let hash_for_b = receipt_hash(&to, &amount, &nonce, contract_b.chain_id());
+		assert_ne!(hash_for_a, hash_for_b);
+	}
+
+	#[test]
+	fn sibling_deployments_with_different_chain_ids_have_different_permit_domains() {
+		ext_reset(|e| e);
+		let mut contract_a = TokenContractInstance{};
+		contract_a.constructor(1000.into(), 1.into());
+		// `ext_reset` below wipes this deployment's storage, so read its chain id first.
+		let chain_id_a = contract_a.chain_id();
+
+		ext_reset(|e| e);
+		let mut contract_b = TokenContractInstance{};
+		contract_b.constructor(1000.into(), 2.into());
+
+		let owner =Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let spender = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		let value = 1000.into();
+		let deadline = 100000.into();
+		let struct_hash = permit_struct_hash(&owner, &spender, &value, &0.into(), &deadline);
+
+		let digest_for_a = eip712_digest(&compute_domain_separator(chain_id_a), &struct_hash);
+		let digest_for_b = eip712_digest(&compute_domain_separator(contract_b.chain_id()), &struct_hash);
+		assert_ne!(digest_for_a, digest_for_b);
+	}
+
+	#[test]
+	fn constructor_caches_the_domain_separator_it_was_constructed_with() {
+		ext_reset(|e| e);
+		let mut contract = TokenContractInstance{};
+		contract.constructor(1000.into(), 42.into());
+
+		assert_eq!(read_domain_separator(), compute_domain_separator(contract.chain_id()));
+	}
+
+	// `mintWithReceipt` and `permit` both end in a call to `ecrecover`, which in turn goes
+	// through `eth::call` to the precompile at address 0x01. `pwasm_test`'s mock `External`
+	// has no hook for stubbing precompile calls or producing a real secp256k1 signature
+	// (this crate has no ECDSA-signing dependency), so the "valid signature" and
+	// "signed by the wrong key" paths can't be driven end-to-end here. What the tests below
+	// do cover is every check that runs *before* `ecrecover`, since those are ordered ahead
+	// of it specifically so a malformed or already-spent request is rejected without ever
+	// reaching the precompile.
+
+	#[test]
+	fn mintWithReceipt_should_reject_a_malformed_signature_without_calling_ecrecover() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		let to = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		let short_sig = vec![0u8; 64];
+		assert_eq!(
+			contract.mintWithReceipt(to, 500.into(), 1.into(), short_sig),
+			Err(TokenError::InvalidSignature)
+		);
+		assert_eq!(contract.totalSupply(), 10000.into());
+		assert_eq!(ext_get().logs().len(), 0);
+	}
+
+	#[test]
+	fn mintWithReceipt_should_reject_an_already_used_nonce_before_checking_the_signature() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		let to = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		let nonce = 1.into();
+		mark_nonce_used(&nonce);
+
+		// A bogus (but correctly-sized) signature would normally be rejected by ecrecover,
+		// but the nonce check runs first, so this never gets that far.
+		let bogus_sig = vec![0u8; 65];
+		assert_eq!(
+			contract.mintWithReceipt(to, 500.into(), nonce, bogus_sig),
+			Err(TokenError::NonceAlreadyUsed)
+		);
+		assert_eq!(contract.totalSupply(), 10000.into());
+		assert_eq!(ext_get().logs().len(), 0);
+	}
+
+	#[test]
+	fn permit_should_reject_an_expired_deadline_before_checking_the_signature() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let spender = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()).timestamp(1_000));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		let deadline = 999.into();
+		// A bogus (but syntactically valid) signature would normally be rejected by
+		// ecrecover, but the deadline check runs first, so this never gets that far.
+		assert_eq!(
+			contract.permit(owner_address, spender, 500.into(), deadline, 0, H256::zero(), H256::zero()),
+			Err(TokenError::DeadlineExpired)
+		);
+		assert_eq!(contract.allowance(owner_address, spender), 0.into());
+	}
+
+	// `permit` can't be driven through the mocked `ecrecover` precompile to produce a
+	// zero signer (see the block comment above), so `is_valid_permit_signer` is exercised
+	// directly instead.
+	#[test]
+	fn is_valid_permit_signer_should_reject_a_zero_signer_even_if_owner_is_also_zero() {
+		assert_eq!(is_valid_permit_signer(Address::zero(), Address::zero()), false);
+	}
+
+	#[test]
+	fn is_valid_permit_signer_should_reject_a_signer_that_does_not_match_owner() {
+		let signer = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let owner = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		assert_eq!(is_valid_permit_signer(signer, owner), false);
+	}
+
+	#[test]
+	fn is_valid_permit_signer_should_accept_a_matching_non_zero_signer() {
+		let signer = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		assert_eq!(is_valid_permit_signer(signer, signer), true);
+	}
+
+	#[test]
+	fn owner_should_be_the_deployer() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+		assert_eq!(contract.owner(), owner_address);
+	}
+
+	#[test]
+	fn owner_should_be_able_to_transfer_ownership() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let new_owner = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+		assert_eq!(contract.transferOwnership(new_owner), Ok(()));
+		assert_eq!(contract.owner(), new_owner);
+	}
+
+	#[test]
+	fn non_owner_should_not_be_able_to_transfer_ownership() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let stranger = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		ext_update(|e| e.sender(stranger));
+		assert_eq!(contract.transferOwnership(stranger), Err(TokenError::Unauthorized));
+		assert_eq!(contract.owner(), owner_address);
+	}
+
+	#[test]
+	fn transfer_ownership_should_reject_the_zero_address() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		assert_eq!(contract.transferOwnership(Address::zero()), Err(TokenError::ZeroAddress));
+		assert_eq!(contract.owner(), owner_address);
+	}
+
+	#[test]
+	fn owner_should_be_able_to_mint_new_tokens() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let recipient = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		assert_eq!(contract.mint(recipient, 500.into()), Ok(()));
+		assert_eq!(contract.balanceOf(recipient), 500.into());
+		assert_eq!(contract.totalSupply(), 10500.into());
+	}
+
+	#[test]
+	fn non_owner_should_not_be_able_to_mint_new_tokens() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		let stranger = Address::from_str("db6fd484cfa46eeeb73c71edee823e4812f9e2e1").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		ext_update(|e| e.sender(stranger));
+		assert_eq!(contract.mint(stranger, 500.into()), Err(TokenError::Unauthorized));
+		assert_eq!(contract.totalSupply(), 10000.into());
+	}
+
+	#[test]
+	fn owner_should_be_able_to_burn_their_own_tokens() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		assert_eq!(contract.burn(4000.into()), Ok(()));
+		assert_eq!(contract.balanceOf(owner_address), 6000.into());
+		assert_eq!(contract.totalSupply(), 6000.into());
+	}
+
+	#[test]
+	fn burn_should_fail_for_more_than_the_owner_balance() {
+		let owner_address = Address::from_str("ea674fdde714fd979de3edf0f56aa9716b898ec8").unwrap();
+		ext_reset(|e| e.sender(owner_address.clone()));
+		let mut contract = TokenContractInstance{};
+		contract.constructor(10000.into(), 1.into());
+
+		assert_eq!(contract.burn(20000.into()), Err(TokenError::InsufficientBalance));
+		assert_eq!(contract.totalSupply(), 10000.into());
+	}
 }